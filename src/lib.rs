@@ -5,8 +5,12 @@
 //! # Features
 //!
 //! - Parsing of expressions with numbers, operators (+, -, *, /), parentheses, as well as dice
-//! expression.
+//!   expressions.
+//! - Named variables (e.g. `"gnosis + 2d6"`), resolved against a lookup at `eval` time.
+//! - Exploding ("again") dice, e.g. `"5d10!"` or `"5d10!9"` for nine-again.
+//! - Success-counting dice pools, e.g. `"8d10 >= 8"`, evaluated with `Expression::eval_pool`.
 //! - Optional logging of individual dice rolls through the `DiceLogger` struct.
+//! - `Display` for `Expression`, rendering a parsed tree back to canonical dice notation.
 //!
 //! # Examples
 //! ```
@@ -16,7 +20,7 @@
 //! let expression : Expression = "(12d8 + 34)/2".try_into().unwrap();
 //! let mut logger = DiceLogger::new();
 //!
-//! let result = expression.eval(&mut Some(&mut logger)).unwrap();
+//! let result = expression.eval(&mut Some(&mut logger), &Default::default()).unwrap();
 //!
 //! // The expression should evaluate to a value between 23 and 65, since the minimum roll for 12d8
 //! //is 12 and the maximum is 96.
@@ -26,7 +30,7 @@
 //! # }
 //! ```
 
-use std::{fmt::Display, ops::Deref};
+use std::{collections::HashMap, fmt::Display, ops::Deref};
 
 use rand::{Rng, rng};
 
@@ -43,7 +47,7 @@ use rand::{Rng, rng};
 /// # use dice_parser::DiceLogger;
 /// let expression : dice_parser::Expression = "12d8+34".try_into().unwrap();
 /// let mut logger = DiceLogger::new();
-/// let _ = expression.eval(&mut Some(&mut logger)).unwrap();
+/// let _ = expression.eval(&mut Some(&mut logger), &Default::default()).unwrap();
 ///
 /// assert_eq!(logger.iter().len(), 12);
 /// ```
@@ -96,9 +100,10 @@ impl From<DiceLogger> for Vec<u32> {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 enum Token {
     Number(u32),
+    Ident(String),
     Op(char),
     Eof,
 }
@@ -107,27 +112,52 @@ struct Lexer {
     tokens: Vec<Token>,
 }
 impl Lexer {
-    fn new(input: &str) -> Result<Self, String> {
+    fn new(input: &str) -> Result<Self, DiceError> {
         let mut tokens: Vec<Token> = Vec::new();
         let mut iterator = input.chars().filter(|it| !it.is_whitespace()).peekable();
 
         while iterator.peek().is_some() {
             let c = iterator.next().unwrap();
             match c {
-                '+' | '-' | '*' | '/' | '(' | ')' => tokens.push(Token::Op(c)),
-                'd' | 'D' => tokens.push(Token::Op('d')),
+                '+' | '-' | '*' | '/' | '(' | ')' | '!' | '>' | '=' => tokens.push(Token::Op(c)),
+                // `d`/`D` is the dice operator unless it's immediately followed by a letter or
+                // `_` (e.g. `dexterity`); then it's the start of a variable name instead, so fall
+                // through to identifier lexing below. A following digit keeps it the dice operator.
+                'd' | 'D'
+                    if !matches!(iterator.peek(), Some(next) if next.is_ascii_alphabetic() || *next == '_') =>
+                {
+                    tokens.push(Token::Op('d'));
+                }
                 '0'..='9' => {
                     let mut number = c.to_digit(10).unwrap();
                     while let Some(&next) = iterator.peek() {
-                        if next.is_digit(10) {
-                            number = number * 10 + iterator.next().unwrap().to_digit(10).unwrap();
+                        if next.is_ascii_digit() {
+                            let digit = iterator.next().unwrap().to_digit(10).unwrap();
+                            number = number
+                                .checked_mul(10)
+                                .and_then(|n| n.checked_add(digit))
+                                .ok_or(DiceError::NumberOverflow)?;
                         } else {
                             break;
                         }
                     }
                     tokens.push(Token::Number(number));
                 }
-                _ => return Err(format!("Unexpected character: {}", c)),
+                // Any other letter (or `d`/`D` followed by a letter or `_`, per above)
+                // starts an identifier (a variable name) rather than the dice operator.
+                c if c.is_ascii_alphabetic() => {
+                    let mut ident = String::new();
+                    ident.push(c);
+                    while let Some(&next) = iterator.peek() {
+                        if next.is_alphanumeric() || next == '_' {
+                            ident.push(iterator.next().unwrap());
+                        } else {
+                            break;
+                        }
+                    }
+                    tokens.push(Token::Ident(ident));
+                }
+                _ => return Err(DiceError::UnexpectedChar(c)),
             }
         }
 
@@ -138,10 +168,81 @@ impl Lexer {
         self.tokens.pop().unwrap_or(Token::Eof)
     }
     fn peek(&mut self) -> Token {
-        self.tokens.last().copied().unwrap_or(Token::Eof)
+        self.tokens.last().cloned().unwrap_or(Token::Eof)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// The error type returned when lexing, parsing, or evaluating a dice expression fails.
+pub enum DiceError {
+    /// A character that isn't part of the dice grammar, e.g. `"2 & 6"`.
+    UnexpectedChar(char),
+    /// A number was expected, but something else (or nothing) was found.
+    ExpectedNumber,
+    /// An operator was expected after an operand, but a number or variable was found instead.
+    ExpectedOperator,
+    /// A `(` was never matched by a closing `)`.
+    UnclosedParen,
+    /// The expression parsed successfully, but characters were left over afterwards, e.g.
+    /// `"2+3)"`.
+    UnconsumedInput,
+    /// An operator that isn't recognized.
+    UnknownOperator(char),
+    /// Division by zero.
+    DivideByZero,
+    /// A variable was referenced that isn't present in the lookup passed to `eval`.
+    UndefinedVariable(String),
+    /// `">="` wasn't followed by a valid pool target number, e.g. `"8d10 >="`.
+    InvalidPoolSyntax,
+    /// Exploding dice need a threshold of at least 2 on a die with at least 2 sides, otherwise
+    /// every roll would hit the threshold and the explosion would never terminate.
+    UnboundedExplosion { sides: u32, threshold: u32 },
+    /// `eval` was called on an `Expression::Pool`; use `eval_pool` instead.
+    PoolRequiresEvalPool,
+    /// `eval_pool` was called on anything other than an `Expression::Pool`.
+    NotAPool,
+    /// A numeric literal is too large to fit in a `u32`.
+    NumberOverflow,
+}
+
+impl Display for DiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiceError::UnexpectedChar(c) => write!(f, "Unexpected character: {}", c),
+            DiceError::ExpectedNumber => write!(f, "Expected a number"),
+            DiceError::ExpectedOperator => write!(f, "Expected an operator"),
+            DiceError::UnclosedParen => write!(f, "Unclosed parenthesis"),
+            DiceError::UnconsumedInput => {
+                write!(f, "Extraneous input detected after the expression")
+            }
+            DiceError::UnknownOperator(op) => write!(f, "Unknown operator: {}", op),
+            DiceError::DivideByZero => write!(f, "Division by zero"),
+            DiceError::UndefinedVariable(name) => write!(f, "Undefined variable: {}", name),
+            DiceError::InvalidPoolSyntax => {
+                write!(f, "Expected a pool comparison like \">= 8\"")
+            }
+            DiceError::UnboundedExplosion { sides, threshold } => write!(
+                f,
+                "Dice can't explode on a threshold of {} with {} sides, they would never stop rolling",
+                threshold, sides
+            ),
+            DiceError::PoolRequiresEvalPool => write!(
+                f,
+                "Pool expressions (e.g. \"8d10 >= 8\") must be evaluated with eval_pool, not eval"
+            ),
+            DiceError::NotAPool => write!(
+                f,
+                "Only a pool expression (e.g. \"8d10 >= 8\") can be evaluated with eval_pool"
+            ),
+            DiceError::NumberOverflow => {
+                write!(f, "Numeric literal is too large, it must fit in a u32")
+            }
+        }
     }
 }
 
+impl std::error::Error for DiceError {}
+
 #[derive(Clone, Debug)]
 /// Represents a parsed expression, which can be either a number or an operation with operands. The
 /// `eval` method can be used to evaluate the expression, optionally logging any dice rolls that
@@ -152,20 +253,49 @@ impl Lexer {
 /// ```
 /// # use dice_parser::Expression;
 /// let expression : Expression = "12d8+34".try_into().unwrap();
-/// let result = expression.eval(&mut None).unwrap();
+/// let result = expression.eval(&mut None, &Default::default()).unwrap();
 ///
 /// assert!(46.0 <= result && result <= 130.0);
 /// ```
 pub enum Expression {
     Number(u32),
+    /// A named variable, e.g. `gnosis`, resolved against a lookup of stats at `eval` time.
+    Variable(String),
     Operation(char, Vec<Expression>),
+    /// A success-counting dice pool, e.g. `"8d10 >= 8"`: roll the inner expression and count how
+    /// many individual dice meet or exceed the target number. Evaluated with `eval_pool` rather
+    /// than `eval`.
+    Pool(Box<Expression>, u32),
 }
 impl TryFrom<&str> for Expression {
-    type Error = String;
+    type Error = DiceError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut lexer = Lexer::new(value)?;
-        Ok(parse_expression(&mut lexer, 0.)?)
+        let expr = parse_expression(&mut lexer, 0.)?;
+
+        let expr = if matches!(lexer.peek(), Token::Op('>')) {
+            lexer.next();
+            match lexer.next() {
+                Token::Op('=') => {}
+                _ => return Err(DiceError::InvalidPoolSyntax),
+            }
+            let target = match lexer.next() {
+                Token::Number(n) => n,
+                _ => return Err(DiceError::InvalidPoolSyntax),
+            };
+            Expression::Pool(Box::new(expr), target)
+        } else {
+            expr
+        };
+
+        // The whole input must have been consumed by now; anything left over (e.g. the `)` in
+        // `"(2+3"`'s unbalanced cousin `"2+3)"`) is "extraneous input detected".
+        if lexer.next() != Token::Eof {
+            return Err(DiceError::UnconsumedInput);
+        }
+
+        Ok(expr)
     }
 }
 /// The default expression is just the number 0, which evaluates to 0.0.
@@ -176,26 +306,65 @@ impl Default for Expression {
 }
 impl Expression {
     /// Evaluates the expression, returning the result as a `f32`. If a `DiceLogger` is provided,
-    /// any dice rolls that occur during the evaluation will be logged in the logger.
+    /// any dice rolls that occur during the evaluation will be logged in the logger. `variables`
+    /// is consulted whenever the expression contains a named variable (e.g. `"gnosis + 2d6"`).
     ///
     /// # Results
     ///
     /// If the expression is valid, the result will be a `f32` representing the evaluated value of
-    /// the expression. If the expression is invalid (e.g., contains an unknown operator), an error
-    /// message will be returned as a `String`.
-    pub fn eval(&self, dice_logger: &mut Option<&mut DiceLogger>) -> Result<f32, String> {
+    /// the expression. If the expression is invalid (e.g., contains an unknown operator, or
+    /// references a variable that isn't in `variables`), a `DiceError` is returned.
+    pub fn eval(
+        &self,
+        dice_logger: &mut Option<&mut DiceLogger>,
+        variables: &HashMap<String, f32>,
+    ) -> Result<f32, DiceError> {
         Ok(match self {
             Expression::Number(n) => *n as f32,
 
+            Expression::Variable(name) => *variables
+                .get(name)
+                .ok_or_else(|| DiceError::UndefinedVariable(name.clone()))?,
+
+            Expression::Pool(..) => return Err(DiceError::PoolRequiresEvalPool),
+
+            // Exploding ("again") dice: operands are [amount, sides] and, optionally, an
+            // explicit again-threshold (e.g. `5d10!9` for nine-again); it defaults to `sides`
+            // when omitted. Evaluated before the shared `lhs`/`rhs` computation below, since
+            // amount/sides can themselves be dice subexpressions (e.g. `(2d6)d10!`) that must
+            // only be rolled once.
+            Expression::Operation('!', operands) => {
+                let amount = operands[0].eval(dice_logger, variables)? as u32;
+                let sides = operands[1].eval(dice_logger, variables)? as u32;
+                let threshold = match operands.get(2) {
+                    Some(threshold) => threshold.eval(dice_logger, variables)? as u32,
+                    None => sides,
+                };
+
+                let (sum, mut collection) = roll_exploding_dice(amount, sides, threshold)?;
+                if let Some(dice_logger) = dice_logger {
+                    dice_logger.append(&mut collection);
+                }
+                sum as f32
+            }
+
             Expression::Operation(operator, operands) => {
-                let lhs = operands.first().unwrap().eval(dice_logger)?;
-                let rhs = operands.last().unwrap().eval(dice_logger)?;
+                let [lhs, rhs] = operands.as_slice() else {
+                    return Err(DiceError::ExpectedNumber);
+                };
+                let lhs = lhs.eval(dice_logger, variables)?;
+                let rhs = rhs.eval(dice_logger, variables)?;
 
                 match operator {
                     '+' => lhs + rhs,
                     '-' => lhs - rhs,
                     '*' => lhs * rhs,
-                    '/' => lhs / rhs,
+                    '/' => {
+                        if rhs == 0.0 {
+                            return Err(DiceError::DivideByZero);
+                        }
+                        lhs / rhs
+                    }
                     'd' => {
                         let (sum, mut collection) = roll_dice(lhs as u32, rhs as u32);
                         if let Some(dice_logger) = dice_logger {
@@ -204,39 +373,181 @@ impl Expression {
                         sum as f32
                     }
 
-                    _ => return Err(format!("Unknown operator: {}", operator)),
+                    _ => return Err(DiceError::UnknownOperator(*operator)),
                 }
             }
         })
     }
+
+    /// Evaluates a success-counting dice pool (e.g. `"8d10 >= 8"`), rolling the inner expression
+    /// and counting how many individual dice meet or exceed the target number. Only valid on
+    /// `Expression::Pool`; any other variant returns an error.
+    pub fn eval_pool(&self, variables: &HashMap<String, f32>) -> Result<RolledPool, DiceError> {
+        let Expression::Pool(inner, target) = self else {
+            return Err(DiceError::NotAPool);
+        };
+
+        let mut logger = DiceLogger::new();
+        inner.eval(&mut Some(&mut logger), variables)?;
+        let rolls: Vec<u32> = logger.into();
+        let successes = rolls.iter().filter(|&&roll| roll >= *target).count();
+
+        Ok(RolledPool {
+            exceptional: successes >= 5,
+            successes,
+            rolls,
+        })
+    }
+}
+
+/// Renders the expression back to dice notation, adding parentheses only where required by
+/// `operation_priority` so that `expr.to_string()` re-parses to an equivalent tree.
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Number(n) => write!(f, "{}", n),
+            Expression::Variable(name) => write!(f, "{}", name),
+            Expression::Pool(inner, target) => write!(f, "{} >= {}", inner, target),
+
+            // Exploding dice: operands are [amount, sides] and, optionally, an explicit
+            // again-threshold. Amount/sides bind like the `d` operator they stand in for, so
+            // they're parenthesized under the same rule as the general branch below. A variable
+            // next to `d` is always parenthesized, since `gnosisd6` or `2dgnosis` would lex as
+            // a single identifier.
+            Expression::Operation('!', operands) => {
+                let (l_bp, r_bp) = operation_priority('d').unwrap();
+                let amount = &operands[0];
+                let sides = &operands[1];
+
+                if display_priority(amount) < l_bp || matches!(amount, Expression::Variable(_)) {
+                    write!(f, "({})", amount)?;
+                } else {
+                    write!(f, "{}", amount)?;
+                }
+                write!(f, "d")?;
+                if display_priority(sides) < r_bp || matches!(sides, Expression::Variable(_)) {
+                    write!(f, "({})", sides)?;
+                } else {
+                    write!(f, "{}", sides)?;
+                }
+                write!(f, "!")?;
+                if let Some(threshold) = operands.get(2) {
+                    write!(f, "{}", threshold)?;
+                }
+                Ok(())
+            }
+
+            Expression::Operation(op, operands) => {
+                let (l_bp, r_bp) = operation_priority(*op).unwrap();
+                let lhs = &operands[0];
+                let rhs = &operands[1];
+                let is_dice_variable =
+                    |e: &Expression| *op == 'd' && matches!(e, Expression::Variable(_));
+
+                if display_priority(lhs) < l_bp || is_dice_variable(lhs) {
+                    write!(f, "({})", lhs)?;
+                } else {
+                    write!(f, "{}", lhs)?;
+                }
+                write!(f, "{}", op)?;
+                if display_priority(rhs) < r_bp || is_dice_variable(rhs) {
+                    write!(f, "({})", rhs)?;
+                } else {
+                    write!(f, "{}", rhs)?;
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
-fn operation_priority(op: char) -> Result<(f32, f32), String> {
+#[derive(Clone, Debug, PartialEq)]
+/// The result of evaluating a success-counting dice pool: the individual rolls, how many of them
+/// met or exceeded the target number, and whether the result was exceptional (5 or more
+/// successes).
+pub struct RolledPool {
+    pub rolls: Vec<u32>,
+    pub successes: usize,
+    pub exceptional: bool,
+}
+
+impl Display for RolledPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} \u{2192} {} success{}",
+            self.rolls
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.successes,
+            if self.successes == 1 { "" } else { "es" }
+        )
+    }
+}
+
+fn operation_priority(op: char) -> Result<(f32, f32), DiceError> {
     Ok(match op {
         '+' | '-' => (1.0, 1.1),
         '*' | '/' => (2.0, 2.1),
-        'd' => (3.0, 3.1),
-        _ => return Err(format!("Unknown operator: {:?}", op)),
+        'd' | '!' => (3.0, 3.1),
+        _ => return Err(DiceError::UnknownOperator(op)),
     })
 }
 
-fn parse_expression(lexer: &mut Lexer, min_bp: f32) -> Result<Expression, String> {
+/// The binding power an expression presents to an enclosing operator: atoms (numbers, variables)
+/// bind infinitely tightly and never need parenthesizing, operations bind as per
+/// `operation_priority`.
+fn display_priority(expr: &Expression) -> f32 {
+    match expr {
+        Expression::Operation(op, _) => operation_priority(*op).map_or(f32::INFINITY, |(l, _)| l),
+        _ => f32::INFINITY,
+    }
+}
+
+fn parse_expression(lexer: &mut Lexer, min_bp: f32) -> Result<Expression, DiceError> {
     let mut lhs = match lexer.next() {
         Token::Number(n) => Expression::Number(n),
+        Token::Ident(name) => Expression::Variable(name),
         Token::Op('(') => {
             let lhs = parse_expression(lexer, 0.0)?;
-            assert_eq!(lexer.next(), Token::Op(')'));
+            if lexer.next() != Token::Op(')') {
+                return Err(DiceError::UnclosedParen);
+            }
             lhs
         }
-        t => return Err(format!("Expected a number, found: {:?}", t)),
+        _ => return Err(DiceError::ExpectedNumber),
     };
 
     loop {
+        // `!` is a postfix modifier on a dice roll (exploding/"again" dice), not a binary
+        // operator, so it's handled separately from the `operation_priority` table below. If it
+        // doesn't follow a dice roll, leave it unconsumed for the caller to deal with.
+        if matches!(lexer.peek(), Token::Op('!')) {
+            if !matches!(lhs, Expression::Operation('d', _)) {
+                break;
+            }
+            let Expression::Operation('d', mut operands) = lhs else {
+                unreachable!()
+            };
+            lexer.next();
+            if let Token::Number(threshold) = lexer.peek() {
+                lexer.next();
+                operands.push(Expression::Number(threshold));
+            }
+            lhs = Expression::Operation('!', operands);
+            continue;
+        }
+
         let op = match lexer.peek() {
             Token::Eof => break,
             Token::Op(')') => break,
+            // `>=` is the pool-mode suffix (e.g. `8d10 >= 8`), handled by the caller once the
+            // whole expression has been parsed, not a binary operator here.
+            Token::Op('>') => break,
             Token::Op(op) => op,
-            t => return Err(format!("Expected an operator, found: {:?}", t)),
+            _ => return Err(DiceError::ExpectedOperator),
         };
         let (l_bp, r_bp) = operation_priority(op)?;
         if l_bp < min_bp {
@@ -255,6 +566,32 @@ fn roll_dice(amount: u32, sides: u32) -> (u32, Vec<u32>) {
     (sum, results)
 }
 
+/// Rolls `amount` dice with `sides` faces, then "explodes": every die in a batch that lands at or
+/// above `threshold` causes one more die to be rolled, and so on until a batch rolls no more
+/// hits. This is the Chronicles of Darkness "again" mechanic (e.g. nine-again is
+/// `threshold == 9`). Returns the running total and every individual roll, including the dice
+/// added by explosions.
+fn roll_exploding_dice(
+    amount: u32,
+    sides: u32,
+    threshold: u32,
+) -> Result<(u32, Vec<u32>), DiceError> {
+    if sides <= 1 || threshold <= 1 {
+        return Err(DiceError::UnboundedExplosion { sides, threshold });
+    }
+
+    let mut rolls = Vec::new();
+    let mut pending = amount;
+    while pending > 0 {
+        let (_, mut batch) = roll_dice(pending, sides);
+        pending = batch.iter().filter(|&&roll| roll >= threshold).count() as u32;
+        rolls.append(&mut batch);
+    }
+
+    let sum = rolls.iter().sum();
+    Ok((sum, rolls))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,13 +600,215 @@ mod tests {
     fn test_logger() {
         let mut logger = DiceLogger::new();
         let expression: Expression = ("12d8+34").try_into().unwrap();
-        expression.eval(&mut Some(&mut logger)).unwrap();
+        expression
+            .eval(&mut Some(&mut logger), &Default::default())
+            .unwrap();
 
         assert_eq!(logger.iter().len(), 12);
     }
     #[test]
     fn test_evaluation() {
         let expression: Expression = ("15+30000/(2*10)").try_into().unwrap();
-        assert_eq!(expression.eval(&mut None).unwrap(), 1515.0);
+        assert_eq!(expression.eval(&mut None, &Default::default()).unwrap(), 1515.0);
+    }
+
+    #[test]
+    fn test_variable_binding() {
+        let expression: Expression = ("gnosis + 2d6").try_into().unwrap();
+        let mut variables = HashMap::new();
+        variables.insert("gnosis".to_string(), 5.0);
+
+        let result = expression.eval(&mut None, &variables).unwrap();
+        assert!((7.0..=17.0).contains(&result));
+    }
+
+    #[test]
+    fn test_variable_starting_with_d_is_not_mistaken_for_dice_operator() {
+        let expression: Expression = ("dexterity + 2d6").try_into().unwrap();
+        let mut variables = HashMap::new();
+        variables.insert("dexterity".to_string(), 3.0);
+
+        let result = expression.eval(&mut None, &variables).unwrap();
+        assert!((5.0..=15.0).contains(&result));
+    }
+
+    #[test]
+    fn test_undefined_variable() {
+        let expression: Expression = ("willpower + 1").try_into().unwrap();
+        assert!(expression.eval(&mut None, &Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_exploding_dice() {
+        let mut logger = DiceLogger::new();
+        let expression: Expression = ("5d10!").try_into().unwrap();
+        let result = expression
+            .eval(&mut Some(&mut logger), &Default::default())
+            .unwrap();
+
+        // At least the 5 initial dice were rolled, every roll is in range, and the logged total
+        // matches the evaluated sum.
+        assert!(logger.iter().len() >= 5);
+        assert!(logger.iter().all(|&roll| (1..=10).contains(&roll)));
+        assert_eq!(result, logger.iter().sum::<u32>() as f32);
+    }
+
+    #[test]
+    fn test_exploding_dice_explicit_threshold() {
+        let expression: Expression = ("20d10!9").try_into().unwrap();
+        assert!(expression.eval(&mut None, &Default::default()).is_ok());
+    }
+
+    #[test]
+    fn test_exploding_dice_on_d1_is_refused() {
+        let expression: Expression = ("3d1!").try_into().unwrap();
+        assert!(expression.eval(&mut None, &Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_exploding_dice_with_dice_subexpression_rolls_each_die_once() {
+        let mut logger = DiceLogger::new();
+        // Amount is `3d1`, which always rolls three 1s; the explicit threshold of 11 is
+        // unreachable on a d10, so the 3 sides-dice never explode either. The logger should
+        // therefore contain exactly 3 (deterministic) amount-dice rolls plus 3 sides-dice rolls.
+        // If `eval` evaluated the amount subexpression twice (once for the shared `lhs`/`rhs`
+        // computation, once more inside the `!` arm), it would log 3 extra 1s.
+        let expression: Expression = ("(3d1)d10!11").try_into().unwrap();
+        expression
+            .eval(&mut Some(&mut logger), &Default::default())
+            .unwrap();
+
+        assert_eq!(logger.iter().len(), 6);
+    }
+
+    #[test]
+    fn test_pool() {
+        let expression: Expression = ("8d10 >= 8").try_into().unwrap();
+        let pool = expression.eval_pool(&Default::default()).unwrap();
+
+        assert_eq!(pool.rolls.len(), 8);
+        assert!(pool.rolls.iter().all(|&roll| (1..=10).contains(&roll)));
+        assert_eq!(
+            pool.successes,
+            pool.rolls.iter().filter(|&&roll| roll >= 8).count()
+        );
+        assert_eq!(pool.exceptional, pool.successes >= 5);
+    }
+
+    #[test]
+    fn test_pool_display() {
+        let pool = RolledPool {
+            rolls: vec![7, 2, 9, 10],
+            successes: 2,
+            exceptional: false,
+        };
+        assert_eq!(pool.to_string(), "7, 2, 9, 10 \u{2192} 2 successes");
+    }
+
+    #[test]
+    fn test_eval_on_pool_expression_is_an_error() {
+        let expression: Expression = ("8d10 >= 8").try_into().unwrap();
+        assert!(expression.eval(&mut None, &Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_eval_pool_on_non_pool_expression_is_an_error() {
+        let expression: Expression = ("2d6").try_into().unwrap();
+        assert!(expression.eval_pool(&Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_unclosed_paren_is_an_error_not_a_panic() {
+        assert_eq!(
+            Expression::try_from("(2d6").unwrap_err(),
+            DiceError::UnclosedParen
+        );
+    }
+
+    #[test]
+    fn test_unconsumed_input_is_an_error() {
+        assert_eq!(
+            Expression::try_from("2+3)").unwrap_err(),
+            DiceError::UnconsumedInput
+        );
+    }
+
+    #[test]
+    fn test_trailing_operator_is_an_error_not_a_panic() {
+        assert_eq!(
+            Expression::try_from("2+").unwrap_err(),
+            DiceError::ExpectedNumber
+        );
+    }
+
+    #[test]
+    fn test_divide_by_zero() {
+        let expression: Expression = ("1/0").try_into().unwrap();
+        assert_eq!(
+            expression.eval(&mut None, &Default::default()),
+            Err(DiceError::DivideByZero)
+        );
+    }
+
+    #[test]
+    fn test_number_just_within_u32_bounds_parses() {
+        let literal = u32::MAX.to_string();
+        let expression: Expression = literal.as_str().try_into().unwrap();
+        assert_eq!(
+            expression.eval(&mut None, &Default::default()).unwrap(),
+            u32::MAX as f32
+        );
+    }
+
+    #[test]
+    fn test_number_just_over_u32_bounds_is_an_error_not_garbage() {
+        let literal = (u32::MAX as u64 + 1).to_string();
+        assert_eq!(
+            Expression::try_from(literal.as_str()).unwrap_err(),
+            DiceError::NumberOverflow
+        );
+    }
+
+    #[test]
+    fn test_display_simple_dice() {
+        let expression: Expression = "2d6".try_into().unwrap();
+        assert_eq!(expression.to_string(), "2d6");
+    }
+
+    #[test]
+    fn test_display_omits_unneeded_parens() {
+        let expression: Expression = "2-3-4".try_into().unwrap();
+        assert_eq!(expression.to_string(), "2-3-4");
+    }
+
+    #[test]
+    fn test_display_keeps_needed_parens() {
+        let expression: Expression = "(2+3)*4".try_into().unwrap();
+        assert_eq!(expression.to_string(), "(2+3)*4");
+    }
+
+    #[test]
+    fn test_display_round_trips_through_reparse() {
+        for source in [
+            "2d6",
+            "(12d8+34)/2",
+            "gnosis+2d6",
+            "5d10!9",
+            "(2+3)d6!",
+            "2d(1+5)!",
+        ] {
+            let expression: Expression = source.try_into().unwrap();
+            let reparsed: Expression = expression.to_string().as_str().try_into().unwrap();
+            assert_eq!(reparsed.to_string(), expression.to_string());
+        }
+    }
+
+    #[test]
+    fn test_display_keeps_needed_parens_on_exploding_dice_operands() {
+        let expression: Expression = "(2+3)d6!".try_into().unwrap();
+        assert_eq!(expression.to_string(), "(2+3)d6!");
+
+        let expression: Expression = "2d(1+5)!".try_into().unwrap();
+        assert_eq!(expression.to_string(), "2d(1+5)!");
     }
 }